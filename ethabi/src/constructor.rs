@@ -0,0 +1,52 @@
+//! Contract constructor specification.
+
+use encode;
+use {Bytes, ErrorKind, Param, ParamType, Result, Token};
+
+/// Contract constructor specification.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Constructor {
+	/// Constructor input.
+	pub inputs: Vec<Param>,
+}
+
+impl Constructor {
+	/// Returns all input params of given constructor.
+	fn input_param_types(&self) -> Vec<ParamType> {
+		self.inputs.iter().map(|p| p.kind.clone()).collect()
+	}
+
+	/// Prepares constructor calldata: the ABI-encoded args only, with no
+	/// selector, meant to be appended to the contract's deploy bytecode.
+	pub fn encode_input(&self, tokens: &[Token]) -> Result<Bytes> {
+		let params = self.input_param_types();
+
+		if !Token::types_check(tokens, &params) {
+			return Err(ErrorKind::InvalidData.into());
+		}
+
+		Ok(encode(tokens))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use {Constructor, Param, ParamType, Token};
+
+	#[test]
+	fn test_constructor_encode_input() {
+		let constructor = Constructor {
+			inputs: vec![Param {
+				name: "a".to_owned(),
+				kind: ParamType::Uint(32),
+				components: vec![],
+			}],
+		};
+
+		let mut uint = [0u8; 32];
+		uint[31] = 69;
+		let encoded = constructor.encode_input(&[Token::Uint(uint.into())]).unwrap();
+		let expected = hex!("0000000000000000000000000000000000000000000000000000000000000045").to_vec();
+		assert_eq!(encoded, expected);
+	}
+}
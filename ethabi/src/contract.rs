@@ -0,0 +1,174 @@
+//! A full contract ABI: its constructor, functions, and events.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde_json::Value;
+
+use {Constructor, ErrorKind, Event, Function, Result};
+
+/// A full contract ABI, as deserialized from a JSON array of function,
+/// constructor, event, and fallback/receive entries.
+///
+/// Solidity allows function (and event) overloading, so both are stored as
+/// `name -> Vec<_>` rather than `name -> _`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Contract {
+	/// Constructor, if the ABI declares one.
+	pub constructor: Option<Constructor>,
+	/// Functions, keyed by name; more than one entry means the function is overloaded.
+	pub functions: HashMap<String, Vec<Function>>,
+	/// Events, keyed by name; more than one entry means the event is overloaded.
+	pub events: HashMap<String, Vec<Event>>,
+	/// Whether the ABI declares a fallback function.
+	pub fallback: bool,
+	/// Whether the ABI declares a `receive` function.
+	pub receive: bool,
+}
+
+impl Contract {
+	/// Returns every overload registered under `name`.
+	pub fn functions(&self, name: &str) -> Option<&[Function]> {
+		self.functions.get(name).map(Vec::as_slice)
+	}
+
+	/// Returns the single function registered under `name`. Fails if there
+	/// is none, or if `name` is overloaded and a selector is needed to
+	/// disambiguate instead.
+	pub fn function(&self, name: &str) -> Result<&Function> {
+		match self.functions.get(name).map(Vec::as_slice) {
+			Some(fns) if fns.len() == 1 => Ok(&fns[0]),
+			_ => Err(ErrorKind::InvalidData.into()),
+		}
+	}
+
+	/// Finds the function whose selector is `selector`, disambiguating overloads.
+	pub fn function_with_selector(&self, selector: [u8; 4]) -> Option<&Function> {
+		self.functions
+			.values()
+			.flat_map(|fns| fns.iter())
+			.find(|f| f.short_signature() == selector)
+	}
+
+	/// Returns every overload registered under `name`.
+	pub fn events(&self, name: &str) -> Option<&[Event]> {
+		self.events.get(name).map(Vec::as_slice)
+	}
+}
+
+impl<'de> Deserialize<'de> for Contract {
+	fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let entries = Vec::<Value>::deserialize(deserializer)?;
+		let mut contract = Contract::default();
+
+		for entry in entries {
+			let kind = entry
+				.get("type")
+				.and_then(Value::as_str)
+				.unwrap_or("function")
+				.to_owned();
+
+			match kind.as_str() {
+				"function" => {
+					let function: Function = Function::deserialize(entry).map_err(DeError::custom)?;
+					contract.functions.entry(function.name.clone()).or_insert_with(Vec::new).push(function);
+				}
+				"constructor" => {
+					let constructor: Constructor = Constructor::deserialize(entry).map_err(DeError::custom)?;
+					contract.constructor = Some(constructor);
+				}
+				"event" => {
+					let event: Event = Event::deserialize(entry).map_err(DeError::custom)?;
+					contract.events.entry(event.name.clone()).or_insert_with(Vec::new).push(event);
+				}
+				"fallback" => contract.fallback = true,
+				"receive" => contract.receive = true,
+				other => return Err(DeError::custom(UnknownEntryType(other.to_owned()))),
+			}
+		}
+
+		Ok(contract)
+	}
+}
+
+#[derive(Debug)]
+struct UnknownEntryType(String);
+
+impl fmt::Display for UnknownEntryType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "unknown ABI entry type `{}`", self.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json;
+	use Contract;
+
+	#[test]
+	fn contract_deserialization_groups_overloads_by_name() {
+		let s = r#"[
+			{
+				"type": "constructor",
+				"inputs": [{"name": "owner", "type": "address"}]
+			},
+			{
+				"type": "function",
+				"name": "transfer",
+				"inputs": [{"name": "to", "type": "address"}],
+				"outputs": [],
+				"stateMutability": "nonpayable"
+			},
+			{
+				"type": "function",
+				"name": "transfer",
+				"inputs": [
+					{"name": "to", "type": "address"},
+					{"name": "amount", "type": "uint256"}
+				],
+				"outputs": [],
+				"stateMutability": "nonpayable"
+			},
+			{
+				"type": "event",
+				"name": "Transfer",
+				"inputs": [
+					{"name": "to", "type": "address", "indexed": true}
+				],
+				"anonymous": false
+			},
+			{
+				"type": "fallback"
+			}
+		]"#;
+
+		let contract: Contract = serde_json::from_str(s).unwrap();
+
+		assert!(contract.constructor.is_some());
+		assert_eq!(contract.functions("transfer").unwrap().len(), 2);
+		assert!(contract.function("transfer").is_err());
+		assert_eq!(contract.events("Transfer").unwrap().len(), 1);
+		assert!(contract.fallback);
+		assert!(!contract.receive);
+	}
+
+	#[test]
+	fn legacy_constant_bool_defaults_function_type() {
+		let s = r#"[
+			{
+				"name": "balanceOf",
+				"inputs": [{"name": "who", "type": "address"}],
+				"outputs": [{"name": "", "type": "uint256"}],
+				"constant": true
+			}
+		]"#;
+
+		let contract: Contract = serde_json::from_str(s).unwrap();
+		let function = contract.function("balanceOf").unwrap();
+		assert!(function.constant);
+	}
+}
@@ -0,0 +1,149 @@
+//! Contract event specification and log decoding.
+
+use decode;
+use signature::long_signature;
+use {ErrorKind, EventParam, H256, ParamType, Result, Token};
+
+/// Contract event specification.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Event {
+	/// Event name.
+	pub name: String,
+	/// Event input params.
+	pub inputs: Vec<EventParam>,
+	/// If anonymous, the topic list does not contain the event's own signature.
+	#[serde(default)]
+	pub anonymous: bool,
+}
+
+impl Event {
+	/// Returns all input params of given event.
+	fn input_param_types(&self) -> Vec<ParamType> {
+		self.inputs.iter().map(|p| p.kind.clone()).collect()
+	}
+
+	/// Returns the topic0 hash this event's logs are emitted under, i.e.
+	/// `keccak256("name(type,type,...)")`. Anonymous events have no such
+	/// topic, since the full param list replaces it in the topic array.
+	pub fn signature(&self) -> H256 {
+		long_signature(&self.name, &self.input_param_types())
+	}
+
+	/// Splits a raw log's `topics` and `data` back into `(param name, value)`
+	/// pairs, in the order the event declares its params.
+	///
+	/// Indexed value types are read off `topics` (after topic0, unless the
+	/// event is `anonymous`); indexed reference types (`bytes`/`string`/
+	/// arrays) only ever appear in a topic as their 32-byte keccak hash, so
+	/// they are returned as `Token::FixedBytes` of that hash rather than
+	/// decoded. Non-indexed params are ABI-decoded from `data` in order.
+	pub fn decode_log(&self, topics: &[H256], data: &[u8]) -> Result<Vec<(String, Token)>> {
+		let topics_start = if self.anonymous { 0 } else { 1 };
+
+		let indexed_count = self.inputs.iter().filter(|p| p.indexed).count();
+		if topics.len() != topics_start + indexed_count {
+			return Err(ErrorKind::InvalidData.into());
+		}
+
+		let non_indexed_types: Vec<ParamType> = self
+			.inputs
+			.iter()
+			.filter(|p| !p.indexed)
+			.map(|p| p.kind.clone())
+			.collect();
+		let mut non_indexed_tokens = decode(&non_indexed_types, data)?.into_iter();
+		let mut indexed_topics = topics[topics_start..].iter();
+
+		self.inputs
+			.iter()
+			.map(|param| {
+				let token = if param.indexed {
+					let topic = indexed_topics.next().ok_or(ErrorKind::InvalidData)?;
+					if is_reference_type(&param.kind) {
+						Token::FixedBytes(topic.as_ref().to_vec())
+					} else {
+						decode(&[param.kind.clone()], topic.as_ref())?
+							.pop()
+							.ok_or(ErrorKind::InvalidData)?
+					}
+				} else {
+					non_indexed_tokens.next().ok_or(ErrorKind::InvalidData)?
+				};
+				Ok((param.name.clone(), token))
+			})
+			.collect()
+	}
+}
+
+/// Reference types are never encoded inline in a topic; the EVM hashes them
+/// instead, so decoding a topic for one of these can only recover the hash.
+fn is_reference_type(kind: &ParamType) -> bool {
+	match *kind {
+		ParamType::Bytes | ParamType::String | ParamType::Array(_) | ParamType::FixedArray(..) | ParamType::Tuple(_) => true,
+		_ => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Event;
+	use {EventParam, ParamType, Token};
+
+	#[test]
+	fn test_event_decode_log_with_indexed_value_type() {
+		let event = Event {
+			name: "Transfer".to_owned(),
+			inputs: vec![
+				EventParam {
+					name: "from".to_owned(),
+					kind: ParamType::Address,
+					indexed: true,
+					components: vec![],
+				},
+				EventParam {
+					name: "value".to_owned(),
+					kind: ParamType::Uint(256),
+					indexed: false,
+					components: vec![],
+				},
+			],
+			anonymous: false,
+		};
+
+		let mut from_topic = [0u8; 32];
+		from_topic[12..].copy_from_slice(&hex!("ce397e30544d737195a341291675ec1ecaf19b13"));
+		let mut value = [0u8; 32];
+		value[31] = 42;
+
+		let topics = vec![event.signature(), from_topic.into()];
+		let decoded = event.decode_log(&topics, &value).unwrap();
+
+		assert_eq!(decoded[0].0, "from");
+		assert_eq!(
+			decoded[0].1,
+			Token::Address("ce397e30544d737195a341291675ec1ecaf19b13".parse().unwrap())
+		);
+		assert_eq!(decoded[1].0, "value");
+	}
+
+	#[test]
+	fn test_event_decode_log_with_indexed_fixed_array_yields_its_hash() {
+		let event = Event {
+			name: "Batch".to_owned(),
+			inputs: vec![EventParam {
+				name: "ids".to_owned(),
+				kind: ParamType::FixedArray(Box::new(ParamType::Uint(256)), 2),
+				indexed: true,
+				components: vec![],
+			}],
+			anonymous: false,
+		};
+
+		let topic_hash = [7u8; 32];
+		let topics = vec![event.signature(), topic_hash.into()];
+		let decoded = event.decode_log(&topics, &[]).unwrap();
+
+		assert_eq!(decoded[0].0, "ids");
+		assert_eq!(decoded[0].1, Token::FixedBytes(topic_hash.to_vec()));
+	}
+}
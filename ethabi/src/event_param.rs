@@ -0,0 +1,74 @@
+//! Event param.
+use Param;
+use ParamType;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EventParamIr {
+	/// Param name.
+	pub name: String,
+	/// Param type.
+	#[serde(rename="type")]
+	pub kind: ParamType,
+	/// Whether the param is part of the log's topics or its data.
+	pub indexed: bool,
+	/// Components type for tuple.
+	#[serde(default)]
+	pub components: Vec<Param>,
+}
+
+/// Event param specification.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "EventParamIr")]
+pub struct EventParam {
+	/// Param name.
+	pub name: String,
+	/// Param type.
+	pub kind: ParamType,
+	/// Whether the param is part of the log's topics (`true`) or its data (`false`).
+	pub indexed: bool,
+	/// Components type for tuple.
+	pub components: Vec<Param>,
+}
+
+impl From<EventParamIr> for EventParam {
+	fn from(p: EventParamIr) -> Self {
+		let kind = match p.kind {
+			ParamType::Tuple(_) if p.components.len() > 0 => {
+				let params: Vec<ParamType> = p.components.iter().map(|c| c.kind.clone()).collect();
+				ParamType::Tuple(params)
+			},
+			_ => p.kind,
+		};
+
+		EventParam {
+			name: p.name,
+			kind: kind,
+			indexed: p.indexed,
+			components: p.components,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json;
+	use {EventParam, ParamType};
+
+	#[test]
+	fn event_param_deserialization() {
+		let s = r#"{
+			"name": "foo",
+			"type": "address",
+			"indexed": true
+		}"#;
+
+		let deserialized: EventParam = serde_json::from_str(s).unwrap();
+
+		assert_eq!(deserialized, EventParam {
+			name: "foo".to_owned(),
+			kind: ParamType::Address,
+			indexed: true,
+			components: vec![]
+		});
+	}
+}
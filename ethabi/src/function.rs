@@ -1,10 +1,38 @@
 //! Contract function call builder.
 
 use signature::short_signature;
+use state_mutability::StateMutability;
+use tokenize::{Detokenize, Tokenize};
 use {decode, encode, Bytes, ErrorKind, Param, ParamType, Result, Token};
 
+/// Deserialization shape for a `Function`: the raw ABI may describe
+/// mutability via the modern `stateMutability` field, or via the legacy
+/// `constant`/`payable` booleans, or not at all (in which case it defaults
+/// to non-payable, non-constant). `Function::from` resolves these into a
+/// single `state_mutability`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FunctionIr {
+	/// Function name.
+	pub name: String,
+	/// Function input.
+	pub inputs: Vec<Param>,
+	/// Function output.
+	#[serde(default)]
+	pub outputs: Vec<Param>,
+	/// Legacy `constant` flag.
+	#[serde(default)]
+	pub constant: bool,
+	/// Legacy `payable` flag.
+	#[serde(default)]
+	pub payable: bool,
+	/// Modern state mutability field, supersedes `constant`/`payable` when present.
+	#[serde(default, rename = "stateMutability")]
+	pub state_mutability: Option<StateMutability>,
+}
+
 /// Contract function specification.
 #[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "FunctionIr")]
 pub struct Function {
 	/// Function name.
 	pub name: String,
@@ -13,8 +41,31 @@ pub struct Function {
 	/// Function output.
 	pub outputs: Vec<Param>,
 	/// Constant function.
-	#[serde(default)]
 	pub constant: bool,
+	/// Whether the function reads, writes, or moves value.
+	pub state_mutability: StateMutability,
+}
+
+impl From<FunctionIr> for Function {
+	fn from(f: FunctionIr) -> Self {
+		let state_mutability = f.state_mutability.unwrap_or(if f.constant {
+			StateMutability::View
+		} else if f.payable {
+			StateMutability::Payable
+		} else {
+			StateMutability::NonPayable
+		});
+
+		let constant = state_mutability == StateMutability::View || state_mutability == StateMutability::Pure;
+
+		Function {
+			name: f.name,
+			inputs: f.inputs,
+			outputs: f.outputs,
+			constant,
+			state_mutability,
+		}
+	}
 }
 
 impl Function {
@@ -28,6 +79,12 @@ impl Function {
 		self.outputs.iter().map(|p| p.kind.clone()).collect()
 	}
 
+	/// Returns the 4-byte selector this function is dispatched under, i.e.
+	/// the first four bytes of `keccak256("name(type,type,...)")`.
+	pub fn short_signature(&self) -> [u8; 4] {
+		short_signature(&self.name, &self.input_param_types())
+	}
+
 	/// Prepares ABI function call with given input params.
 	pub fn encode_input(&self, tokens: &[Token]) -> Result<Bytes> {
 		let params = self.input_param_types();
@@ -36,21 +93,75 @@ impl Function {
 			return Err(ErrorKind::InvalidData.into());
 		}
 
-		let signed = short_signature(&self.name, &params).to_vec();
+		let signed = self.short_signature().to_vec();
 		let encoded = encode(tokens);
 		Ok(signed.into_iter().chain(encoded.into_iter()).collect())
 	}
 
+	/// Parses raw calldata off the wire into a list of tokens, checking that
+	/// it was addressed to this function by its leading 4-byte selector.
+	pub fn decode_input(&self, data: &[u8]) -> Result<Vec<Token>> {
+		if data.len() < 4 {
+			return Err(ErrorKind::InvalidData.into());
+		}
+		if data[..4] != self.short_signature() {
+			return Err(ErrorKind::InvalidData.into());
+		}
+		decode(&self.input_param_types(), &data[4..])
+	}
+
 	/// Parses the ABI function output to list of tokens.
 	pub fn decode_output(&self, data: &[u8]) -> Result<Vec<Token>> {
 		decode(&self.output_param_types(), &data)
 	}
+
+	/// Prepares ABI function call with given typed input params, saving callers
+	/// from having to assemble a `Vec<Token>` by hand.
+	pub fn encode_input_typed<T: Tokenize>(&self, args: T) -> Result<Bytes> {
+		self.encode_input(&args.into_tokens())
+	}
+
+	/// Parses the ABI function output straight into a typed Rust value or tuple.
+	pub fn decode_output_typed<D: Detokenize>(&self, data: &[u8]) -> Result<D> {
+		D::from_tokens(self.decode_output(data)?)
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use {Function, Param, ParamType, Token};
 
+	#[test]
+	fn test_function_encode_call_typed() {
+		let interface = Function {
+			name: "baz".to_owned(),
+			inputs: vec![
+				Param {
+					name: "a".to_owned(),
+					kind: ParamType::Uint(32),
+					components: vec![],
+				},
+				Param {
+					name: "b".to_owned(),
+					kind: ParamType::Bool,
+					components: vec![],
+				},
+			],
+			outputs: vec![],
+			constant: false,
+			state_mutability: ::StateMutability::NonPayable,
+		};
+
+		let func = Function::from(interface);
+		let mut uint = [0u8; 32];
+		uint[31] = 69;
+		let encoded = func
+			.encode_input_typed((::U256::from(uint), true))
+			.unwrap();
+		let expected = hex!("cdcd77c000000000000000000000000000000000000000000000000000000000000000450000000000000000000000000000000000000000000000000000000000000001").to_vec();
+		assert_eq!(encoded, expected);
+	}
+
 	#[test]
 	fn test_function_encode_call() {
 		let interface = Function {
@@ -69,6 +180,7 @@ mod tests {
 			],
 			outputs: vec![],
 			constant: false,
+			state_mutability: ::StateMutability::NonPayable,
 		};
 
 		let func = Function::from(interface);
@@ -80,6 +192,36 @@ mod tests {
 		let expected = hex!("cdcd77c000000000000000000000000000000000000000000000000000000000000000450000000000000000000000000000000000000000000000000000000000000001").to_vec();
 		assert_eq!(encoded, expected);
 	}
+
+	#[test]
+	fn test_function_decode_input() {
+		let interface = Function {
+			name: "baz".to_owned(),
+			inputs: vec![
+				Param {
+					name: "a".to_owned(),
+					kind: ParamType::Uint(32),
+					components: vec![],
+				},
+				Param {
+					name: "b".to_owned(),
+					kind: ParamType::Bool,
+					components: vec![],
+				},
+			],
+			outputs: vec![],
+			constant: false,
+			state_mutability: ::StateMutability::NonPayable,
+		};
+
+		let func = Function::from(interface);
+		let mut uint = [0u8; 32];
+		uint[31] = 69;
+		let tokens = vec![Token::Uint(uint.into()), Token::Bool(true)];
+		let calldata = func.encode_input(&tokens).unwrap();
+		let decoded = func.decode_input(&calldata).unwrap();
+		assert_eq!(decoded, tokens);
+	}
 }
 
 
@@ -112,6 +254,7 @@ fn test_function_encode_call_with_tuple() {
 		],
 		outputs: vec![],
 		constant: false,
+		state_mutability: ::StateMutability::NonPayable,
 	};
 
 	let func = Function::from(interface);
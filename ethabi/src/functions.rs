@@ -0,0 +1,87 @@
+//! A selector-indexed table over every `Function` in an ABI.
+
+use std::collections::HashMap;
+
+use {ErrorKind, Function, Result, Token};
+
+/// Indexes a set of `Function`s by their 4-byte selector so that raw calldata
+/// taken off the wire can be matched back to the `Function` that produced it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Functions {
+	by_selector: HashMap<[u8; 4], Function>,
+}
+
+impl Functions {
+	/// Builds a selector table over `functions`.
+	pub fn new(functions: Vec<Function>) -> Self {
+		let by_selector = functions
+			.into_iter()
+			.map(|f| (f.short_signature(), f))
+			.collect();
+		Functions { by_selector }
+	}
+
+	/// Looks up the `Function` whose selector matches the leading 4 bytes of
+	/// `calldata`, then decodes the remaining bytes against its inputs.
+	pub fn decode(&self, calldata: &[u8]) -> Result<(&Function, Vec<Token>)> {
+		if calldata.len() < 4 {
+			return Err(ErrorKind::InvalidData.into());
+		}
+
+		let mut selector = [0u8; 4];
+		selector.copy_from_slice(&calldata[..4]);
+
+		let function = self
+			.by_selector
+			.get(&selector)
+			.ok_or(ErrorKind::InvalidData)?;
+		let tokens = function.decode_input(calldata)?;
+		Ok((function, tokens))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Functions;
+	use {Function, Param, ParamType, Token};
+
+	#[test]
+	fn decodes_calldata_against_the_matching_function() {
+		let baz = Function {
+			name: "baz".to_owned(),
+			inputs: vec![
+				Param {
+					name: "a".to_owned(),
+					kind: ParamType::Uint(32),
+					components: vec![],
+				},
+				Param {
+					name: "b".to_owned(),
+					kind: ParamType::Bool,
+					components: vec![],
+				},
+			],
+			outputs: vec![],
+			constant: false,
+			state_mutability: ::StateMutability::NonPayable,
+		};
+
+		let mut uint = [0u8; 32];
+		uint[31] = 69;
+		let calldata = baz
+			.encode_input(&[Token::Uint(uint.into()), Token::Bool(true)])
+			.unwrap();
+
+		let functions = Functions::new(vec![baz.clone()]);
+		let (decoded_function, tokens) = functions.decode(&calldata).unwrap();
+
+		assert_eq!(decoded_function, &baz);
+		assert_eq!(tokens, vec![Token::Uint(uint.into()), Token::Bool(true)]);
+	}
+
+	#[test]
+	fn rejects_calldata_with_an_unknown_selector() {
+		let functions = Functions::new(vec![]);
+		assert!(functions.decode(&[0xde, 0xad, 0xbe, 0xef]).is_err());
+	}
+}
@@ -0,0 +1,361 @@
+//! Generates typed Rust bindings for every function in an ABI.
+//!
+//! Gated behind the `generator` feature: turning an ABI JSON document into a
+//! registry of calldata-producing methods means pulling in `quote`/`syn` (or
+//! at least string templating), which most consumers of this crate never need.
+
+use {Function, Param, ParamType};
+
+/// A `ParamType` this generator does not yet know how to map onto a Rust type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsupportedType {
+	/// Name of the function the offending parameter belongs to.
+	pub function: String,
+	/// The `ParamType` that has no Rust mapping.
+	pub kind: ParamType,
+}
+
+/// One generated method: a snake_case name, the Rust source for its
+/// calldata-encoding function and output-decoding function, and the source
+/// of any nested tuple structs those two functions reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedFunction {
+	/// snake_case name derived from the ABI function name.
+	pub name: String,
+	/// Rust source implementing calldata encoding for this function.
+	pub encode_source: String,
+	/// Rust source implementing output decoding for this function.
+	pub decode_source: String,
+	/// Source of every nested tuple struct `encode_source`/`decode_source`
+	/// reference (one per `Tuple` input or output parameter, plus one per
+	/// `Tuple` component nested inside such a parameter).
+	pub structs: Vec<String>,
+}
+
+/// Builds a Rust source module from a parsed ABI, one generated method per
+/// `Function`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Generator;
+
+impl Generator {
+	/// Creates a new generator.
+	pub fn new() -> Self {
+		Generator
+	}
+
+	/// Generates one `GeneratedFunction` per `Function` in `functions`,
+	/// returning the first `UnsupportedType` encountered instead of a
+	/// function whose parameters it cannot map to Rust.
+	pub fn generate(&self, functions: &[Function]) -> Result<Vec<GeneratedFunction>, UnsupportedType> {
+		functions.iter().map(|f| self.generate_function(f)).collect()
+	}
+
+	fn generate_function(&self, function: &Function) -> Result<GeneratedFunction, UnsupportedType> {
+		let name = to_snake_case(&function.name);
+		let mut structs = Vec::new();
+
+		let mut rust_args = Vec::with_capacity(function.inputs.len());
+		for param in &function.inputs {
+			rust_args.push(self.rust_param(&function.name, param, &mut structs)?);
+		}
+
+		let args_source = rust_args
+			.iter()
+			.map(|(arg_name, ty)| format!("{}: {}", arg_name, ty))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		let token_pushes = rust_args
+			.iter()
+			.map(|(arg_name, _)| format!("\ttokens.extend(Tokenize::into_tokens({}));\n", arg_name))
+			.collect::<String>();
+
+		let selector = function.short_signature();
+		let encode_source = format!(
+			"pub fn {name}({args}) -> Bytes {{\n\
+			 \tlet selector: [u8; 4] = [{s0}, {s1}, {s2}, {s3}];\n\
+			 \tlet mut tokens: Vec<Token> = Vec::new();\n\
+			 {pushes}\
+			 \tselector.iter().cloned().chain(encode(&tokens)).collect()\n\
+			 }}",
+			name = name,
+			args = args_source,
+			s0 = selector[0],
+			s1 = selector[1],
+			s2 = selector[2],
+			s3 = selector[3],
+			pushes = token_pushes,
+		);
+
+		let mut output_types = Vec::with_capacity(function.outputs.len());
+		for param in &function.outputs {
+			let (_, ty) = self.rust_param(&function.name, param, &mut structs)?;
+			output_types.push(ty);
+		}
+		let output_type = match output_types.len() {
+			0 => "()".to_owned(),
+			1 => output_types[0].clone(),
+			_ => format!("({})", output_types.join(", ")),
+		};
+
+		let decode_source = format!(
+			"pub fn decode_{name}_output(data: &[u8]) -> Result<{output_type}> {{\n\
+			 \tlet tokens = decode(&[{param_types}], data)?;\n\
+			 \tDetokenize::from_tokens(tokens)\n\
+			 }}",
+			name = name,
+			output_type = output_type,
+			param_types = function
+				.outputs
+				.iter()
+				.map(|p| param_type_expr(&p.kind))
+				.collect::<Vec<_>>()
+				.join(", "),
+		);
+
+		Ok(GeneratedFunction {
+			name,
+			encode_source,
+			decode_source,
+			structs,
+		})
+	}
+
+	/// Maps a single ABI `Param` onto a `(name, rust_type)` pair, emitting a
+	/// nested struct definition into `structs` for a top-level `Tuple`.
+	fn rust_param(&self, function: &str, param: &Param, structs: &mut Vec<String>) -> Result<(String, String), UnsupportedType> {
+		let ty = match param.kind {
+			// Only a top-level tuple carries field names (via `param.components`);
+			// the struct generated for it is named after the function and param.
+			ParamType::Tuple(_) => {
+				let struct_name = to_camel_case(&format!("{}_{}", function, param.name));
+				let mut fields = Vec::with_capacity(param.components.len());
+				for component in &param.components {
+					let (field_name, field_ty) = self.rust_param(function, component, structs)?;
+					fields.push(format!("\tpub {}: {},", field_name, field_ty));
+				}
+				structs.push(format!("pub struct {} {{\n{}\n}}", struct_name, fields.join("\n")));
+				struct_name
+			}
+			ref other => self.rust_type(function, other)?,
+		};
+		Ok((to_snake_case(&param.name), ty))
+	}
+
+	/// Maps a non-tuple `ParamType` onto its Rust equivalent. A `Tuple`
+	/// nested inside an array or fixed array is reported as unsupported:
+	/// without a `Param` at that nesting level there are no field names left
+	/// to generate a struct from.
+	fn rust_type(&self, function: &str, kind: &ParamType) -> Result<String, UnsupportedType> {
+		let ty = match *kind {
+			ParamType::Address => "H160".to_owned(),
+			ParamType::Bool => "bool".to_owned(),
+			ParamType::Int(_) | ParamType::Uint(_) => "U256".to_owned(),
+			ParamType::Bytes | ParamType::FixedBytes(_) => "Bytes".to_owned(),
+			ParamType::String => "String".to_owned(),
+			ParamType::Array(ref inner) => format!("Vec<{}>", self.rust_type(function, inner)?),
+			ParamType::FixedArray(ref inner, len) => format!("[{}; {}]", self.rust_type(function, inner)?, len),
+			ParamType::Tuple(_) => {
+				return Err(UnsupportedType {
+					function: function.to_owned(),
+					kind: kind.clone(),
+				})
+			}
+		};
+		Ok(ty)
+	}
+}
+
+/// Converts a Solidity identifier (`balanceOf`) into idiomatic Rust
+/// snake_case (`balance_of`), lowercasing each uppercase letter and
+/// inserting an underscore before it (unless it starts the identifier).
+fn to_snake_case(name: &str) -> String {
+	let mut out = String::with_capacity(name.len() + 4);
+	for (i, ch) in name.chars().enumerate() {
+		if ch.is_uppercase() {
+			if i != 0 {
+				out.push('_');
+			}
+			out.extend(ch.to_lowercase());
+		} else {
+			out.push(ch);
+		}
+	}
+	out
+}
+
+/// Renders a `ParamType` as the Rust expression that reconstructs it, e.g.
+/// `ParamType::FixedArray(Box::new(ParamType::Uint(256)), 2)`. `Debug`
+/// can't be used for this: it omits the `Box::new(...)` wrapper `Array` and
+/// `FixedArray` require, and the `vec![...]` `Tuple` requires, to compile.
+fn param_type_expr(kind: &ParamType) -> String {
+	match *kind {
+		ParamType::Address => "ParamType::Address".to_owned(),
+		ParamType::Bytes => "ParamType::Bytes".to_owned(),
+		ParamType::Bool => "ParamType::Bool".to_owned(),
+		ParamType::String => "ParamType::String".to_owned(),
+		ParamType::Int(len) => format!("ParamType::Int({})", len),
+		ParamType::Uint(len) => format!("ParamType::Uint({})", len),
+		ParamType::FixedBytes(len) => format!("ParamType::FixedBytes({})", len),
+		ParamType::Array(ref inner) => format!("ParamType::Array(Box::new({}))", param_type_expr(inner)),
+		ParamType::FixedArray(ref inner, len) => format!("ParamType::FixedArray(Box::new({}), {})", param_type_expr(inner), len),
+		ParamType::Tuple(ref kinds) => {
+			let inner = kinds.iter().map(param_type_expr).collect::<Vec<_>>().join(", ");
+			format!("ParamType::Tuple(vec![{}])", inner)
+		}
+	}
+}
+
+fn to_camel_case(name: &str) -> String {
+	name.split('_')
+		.map(|part| {
+			let mut chars = part.chars();
+			match chars.next() {
+				Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+				None => String::new(),
+			}
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Generator, UnsupportedType};
+	use {Function, Param, ParamType};
+
+	#[test]
+	fn generates_a_method_per_function() {
+		let functions = vec![Function {
+			name: "transfer".to_owned(),
+			inputs: vec![
+				Param {
+					name: "to".to_owned(),
+					kind: ParamType::Address,
+					components: vec![],
+				},
+				Param {
+					name: "amount".to_owned(),
+					kind: ParamType::Uint(256),
+					components: vec![],
+				},
+			],
+			outputs: vec![],
+			constant: false,
+			state_mutability: ::StateMutability::NonPayable,
+		}];
+
+		let generated = Generator::new().generate(&functions).unwrap();
+		assert_eq!(generated.len(), 1);
+		assert_eq!(generated[0].name, "transfer");
+		assert!(generated[0].encode_source.contains("to: H160"));
+		assert!(generated[0].encode_source.contains("amount: U256"));
+		assert!(generated[0].encode_source.contains("Tokenize::into_tokens(to)"));
+		assert!(generated[0].encode_source.contains("selector: [u8; 4]"));
+		assert!(generated[0].structs.is_empty());
+	}
+
+	#[test]
+	fn converts_camel_case_names_to_snake_case() {
+		let functions = vec![Function {
+			name: "balanceOf".to_owned(),
+			inputs: vec![Param {
+				name: "who".to_owned(),
+				kind: ParamType::Address,
+				components: vec![],
+			}],
+			outputs: vec![Param {
+				name: "".to_owned(),
+				kind: ParamType::Uint(256),
+				components: vec![],
+			}],
+			constant: true,
+			state_mutability: ::StateMutability::View,
+		}];
+
+		let generated = Generator::new().generate(&functions).unwrap();
+		assert_eq!(generated[0].name, "balance_of");
+		assert!(generated[0].decode_source.contains("decode_balance_of_output"));
+	}
+
+	#[test]
+	fn generates_a_nested_struct_for_a_top_level_tuple_param() {
+		let functions = vec![Function {
+			name: "swap".to_owned(),
+			inputs: vec![Param {
+				name: "data".to_owned(),
+				kind: ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Bool]),
+				components: vec![
+					Param {
+						name: "amount".to_owned(),
+						kind: ParamType::Uint(256),
+						components: vec![],
+					},
+					Param {
+						name: "exact".to_owned(),
+						kind: ParamType::Bool,
+						components: vec![],
+					},
+				],
+			}],
+			outputs: vec![],
+			constant: false,
+			state_mutability: ::StateMutability::NonPayable,
+		}];
+
+		let generated = Generator::new().generate(&functions).unwrap();
+		assert_eq!(generated[0].structs.len(), 1);
+		assert!(generated[0].structs[0].contains("struct SwapData"));
+		assert!(generated[0].structs[0].contains("pub amount: U256"));
+		assert!(generated[0].structs[0].contains("pub exact: bool"));
+		assert!(generated[0].encode_source.contains("data: SwapData"));
+	}
+
+	#[test]
+	fn decode_source_wraps_array_output_types_in_box_new() {
+		let functions = vec![Function {
+			name: "getIds".to_owned(),
+			inputs: vec![],
+			outputs: vec![Param {
+				name: "".to_owned(),
+				kind: ParamType::Array(Box::new(ParamType::FixedArray(Box::new(ParamType::Uint(256)), 2))),
+				components: vec![],
+			}],
+			constant: true,
+			state_mutability: ::StateMutability::View,
+		}];
+
+		let generated = Generator::new().generate(&functions).unwrap();
+		assert!(generated[0]
+			.decode_source
+			.contains("ParamType::Array(Box::new(ParamType::FixedArray(Box::new(ParamType::Uint(256)), 2)))"));
+	}
+
+	#[test]
+	fn reports_unsupported_types_instead_of_panicking() {
+		let tuple = ParamType::Tuple(vec![ParamType::Bool]);
+		let functions = vec![Function {
+			name: "batch".to_owned(),
+			inputs: vec![Param {
+				name: "orders".to_owned(),
+				kind: ParamType::Array(Box::new(tuple.clone())),
+				components: vec![Param {
+					name: "filled".to_owned(),
+					kind: ParamType::Bool,
+					components: vec![],
+				}],
+			}],
+			outputs: vec![],
+			constant: false,
+			state_mutability: ::StateMutability::NonPayable,
+		}];
+
+		let err = Generator::new().generate(&functions).unwrap_err();
+		assert_eq!(
+			err,
+			UnsupportedType {
+				function: "batch".to_owned(),
+				kind: tuple,
+			}
+		);
+	}
+}
@@ -0,0 +1,292 @@
+//! Parses Solidity-style signature strings (e.g. `transfer(address,uint256)`
+//! or `swap(address,(uint256,bool) data)`) into `Function`/`Param` values,
+//! and the inverse: rendering a `Function`/`Param` back to that same
+//! canonical form. The canonical rendering is exactly the type list
+//! `signature::short_signature` hashes, so it also pins down the tuple
+//! serialization `test_function_encode_call_with_tuple` exercises.
+
+use {ErrorKind, Function, Param, ParamType, Result};
+
+impl ParamType {
+	/// Renders this type the way it appears inside a Solidity function
+	/// signature: tuples as `(a,b)`, dynamic arrays as `type[]`, fixed-size
+	/// arrays as `type[n]`.
+	pub fn to_signature(&self) -> String {
+		match *self {
+			ParamType::Address => "address".to_owned(),
+			ParamType::Bytes => "bytes".to_owned(),
+			ParamType::FixedBytes(len) => format!("bytes{}", len),
+			ParamType::Int(len) => format!("int{}", len),
+			ParamType::Uint(len) => format!("uint{}", len),
+			ParamType::Bool => "bool".to_owned(),
+			ParamType::String => "string".to_owned(),
+			ParamType::Array(ref kind) => format!("{}[]", kind.to_signature()),
+			ParamType::FixedArray(ref kind, len) => format!("{}[{}]", kind.to_signature(), len),
+			ParamType::Tuple(ref kinds) => {
+				let inner = kinds.iter().map(ParamType::to_signature).collect::<Vec<_>>().join(",");
+				format!("({})", inner)
+			}
+		}
+	}
+}
+
+impl Function {
+	/// Renders this function the way it appears in a Solidity interface,
+	/// e.g. `transfer(address,uint256)`.
+	pub fn signature(&self) -> String {
+		let inputs = self.inputs.iter().map(|p| p.kind.to_signature()).collect::<Vec<_>>().join(",");
+		format!("{}({})", self.name, inputs)
+	}
+}
+
+/// Parses a human-readable signature, e.g. `transfer(address,uint256)`, into
+/// a `Function` with no outputs (signature strings carry no return info).
+pub fn parse_function(signature: &str) -> Result<Function> {
+	let open = signature.find('(').ok_or(ErrorKind::InvalidData)?;
+	if !signature.ends_with(')') {
+		return Err(ErrorKind::InvalidData.into());
+	}
+	let name = signature[..open].trim().to_owned();
+	if name.is_empty() {
+		return Err(ErrorKind::InvalidData.into());
+	}
+	let body = &signature[open + 1..signature.len() - 1];
+
+	let inputs = split_top_level(body)?
+		.iter()
+		.map(|chunk| parse_param(chunk))
+		.collect::<Result<Vec<_>>>()?;
+
+	Ok(Function {
+		name,
+		inputs,
+		outputs: vec![],
+		constant: false,
+		state_mutability: ::StateMutability::NonPayable,
+	})
+}
+
+/// Parses a single `type [name]` or `(type,...) [name]` argument chunk.
+fn parse_param(chunk: &str) -> Result<Param> {
+	let chunk = chunk.trim();
+	if chunk.is_empty() {
+		return Err(ErrorKind::InvalidData.into());
+	}
+
+	if chunk.starts_with('(') {
+		let close = matching_paren(chunk, 0)?;
+		let components = split_top_level(&chunk[1..close])?
+			.iter()
+			.map(|c| parse_param(c))
+			.collect::<Result<Vec<_>>>()?;
+		let component_types = components.iter().map(|p| p.kind.clone()).collect();
+
+		let (suffix, name) = split_array_suffix_and_name(&chunk[close + 1..])?;
+		let kind = apply_array_suffix(ParamType::Tuple(component_types), suffix)?;
+
+		Ok(Param {
+			name,
+			kind,
+			components,
+		})
+	} else {
+		let (type_str, name) = split_type_and_name(chunk);
+		let kind = parse_param_type(type_str)?;
+
+		Ok(Param {
+			name,
+			kind,
+			components: vec![],
+		})
+	}
+}
+
+/// Splits a non-tuple chunk like `uint256[3] amount` into its type portion
+/// (`uint256[3]`) and an optional trailing name (`amount`).
+fn split_type_and_name(chunk: &str) -> (&str, String) {
+	let chunk = chunk.trim();
+	match chunk.find(char::is_whitespace) {
+		Some(idx) => (&chunk[..idx], chunk[idx..].trim().to_owned()),
+		None => (chunk, String::new()),
+	}
+}
+
+/// Splits the text following a tuple's closing `)` (e.g. `[2] data` or
+/// ` data`) into its leading `[]`/`[n]` array suffix and a trailing name.
+/// Unlike `split_type_and_name`, there is no type token to search for here —
+/// the suffix is whatever run of `[...]` groups starts the remainder, and
+/// everything after that is the name.
+fn split_array_suffix_and_name(rest: &str) -> Result<(&str, String)> {
+	let mut idx = 0;
+	while rest[idx..].starts_with('[') {
+		let close = rest[idx..].find(']').ok_or(ErrorKind::InvalidData)?;
+		idx += close + 1;
+	}
+	Ok((&rest[..idx], rest[idx..].trim().to_owned()))
+}
+
+/// Parses a base type together with any `[]`/`[n]` array suffixes, e.g.
+/// `uint256[][3]`.
+fn parse_param_type(type_str: &str) -> Result<ParamType> {
+	let bracket = type_str.find('[');
+	let (base, suffix) = match bracket {
+		Some(idx) => (&type_str[..idx], &type_str[idx..]),
+		None => (type_str, ""),
+	};
+
+	let kind = parse_base_type(base)?;
+	apply_array_suffix(kind, suffix)
+}
+
+fn parse_base_type(base: &str) -> Result<ParamType> {
+	let kind = match base {
+		"address" => ParamType::Address,
+		"bool" => ParamType::Bool,
+		"bytes" => ParamType::Bytes,
+		"string" => ParamType::String,
+		"uint" => ParamType::Uint(256),
+		"int" => ParamType::Int(256),
+		_ if base.starts_with("uint") => ParamType::Uint(base[4..].parse().map_err(|_| ErrorKind::InvalidData)?),
+		_ if base.starts_with("int") => ParamType::Int(base[3..].parse().map_err(|_| ErrorKind::InvalidData)?),
+		_ if base.starts_with("bytes") => ParamType::FixedBytes(base[5..].parse().map_err(|_| ErrorKind::InvalidData)?),
+		_ => return Err(ErrorKind::InvalidData.into()),
+	};
+	Ok(kind)
+}
+
+/// Wraps `kind` in `Array`/`FixedArray` for every `[]`/`[n]` suffix found in
+/// `suffix`, applied left to right (`uint256[2][]` is an array of fixed-size
+/// arrays of `uint256`).
+fn apply_array_suffix(mut kind: ParamType, suffix: &str) -> Result<ParamType> {
+	let mut rest = suffix.trim();
+	while !rest.is_empty() {
+		if !rest.starts_with('[') {
+			return Err(ErrorKind::InvalidData.into());
+		}
+		let close = rest.find(']').ok_or(ErrorKind::InvalidData)?;
+		let len_str = &rest[1..close];
+		kind = if len_str.is_empty() {
+			ParamType::Array(Box::new(kind))
+		} else {
+			ParamType::FixedArray(Box::new(kind), len_str.parse().map_err(|_| ErrorKind::InvalidData)?)
+		};
+		rest = rest[close + 1..].trim();
+	}
+	Ok(kind)
+}
+
+/// Splits `body` on commas that are not nested inside parentheses.
+fn split_top_level(body: &str) -> Result<Vec<&str>> {
+	if body.trim().is_empty() {
+		return Ok(vec![]);
+	}
+
+	let mut parts = Vec::new();
+	let mut depth = 0i32;
+	let mut start = 0;
+
+	for (idx, ch) in body.char_indices() {
+		match ch {
+			'(' => depth += 1,
+			')' => {
+				depth -= 1;
+				if depth < 0 {
+					return Err(ErrorKind::InvalidData.into());
+				}
+			}
+			',' if depth == 0 => {
+				parts.push(&body[start..idx]);
+				start = idx + 1;
+			}
+			_ => {}
+		}
+	}
+	if depth != 0 {
+		return Err(ErrorKind::InvalidData.into());
+	}
+	parts.push(&body[start..]);
+	Ok(parts)
+}
+
+/// Finds the index of the `)` matching the `(` at `open`.
+fn matching_paren(s: &str, open: usize) -> Result<usize> {
+	let mut depth = 0i32;
+	for (idx, ch) in s.char_indices().skip(open) {
+		match ch {
+			'(' => depth += 1,
+			')' => {
+				depth -= 1;
+				if depth == 0 {
+					return Ok(idx);
+				}
+			}
+			_ => {}
+		}
+	}
+	Err(ErrorKind::InvalidData.into())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::parse_function;
+	use {ParamType, Function, Param};
+
+	#[test]
+	fn parses_simple_signature() {
+		let func = parse_function("transfer(address,uint256)").unwrap();
+		assert_eq!(func.name, "transfer");
+		assert_eq!(func.inputs[0].kind, ParamType::Address);
+		assert_eq!(func.inputs[1].kind, ParamType::Uint(256));
+	}
+
+	#[test]
+	fn parses_nested_tuple_with_label() {
+		let func = parse_function("swap(address,(uint256,bool) data)").unwrap();
+		assert_eq!(func.inputs[1].name, "data");
+		assert_eq!(
+			func.inputs[1].kind,
+			ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Bool])
+		);
+		assert_eq!(func.inputs[1].components.len(), 2);
+	}
+
+	#[test]
+	fn parses_named_tuple_array_with_array_suffix() {
+		let func = parse_function("batch((uint256,bool)[2] orders)").unwrap();
+		assert_eq!(func.inputs[0].name, "orders");
+		assert_eq!(
+			func.inputs[0].kind,
+			ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Bool])), 2)
+		);
+	}
+
+	#[test]
+	fn signature_round_trips_through_the_parser() {
+		let func = parse_function("hello(address,(bool,bytes))").unwrap();
+		assert_eq!(func.signature(), "hello(address,(bool,bytes))");
+	}
+
+	#[test]
+	fn signature_matches_the_encode_call_with_tuple_fixture() {
+		let interface = Function {
+			name: "hello".to_owned(),
+			inputs: vec![
+				Param {
+					name: "bar".to_owned(),
+					kind: ParamType::Address,
+					components: vec![],
+				},
+				Param {
+					name: "foo".to_owned(),
+					kind: ParamType::Tuple(vec![ParamType::Bool, ParamType::Bytes]),
+					components: vec![],
+				},
+			],
+			outputs: vec![],
+			constant: false,
+			state_mutability: ::StateMutability::NonPayable,
+		};
+
+		assert_eq!(interface.signature(), "hello(address,(bool,bytes))");
+	}
+}
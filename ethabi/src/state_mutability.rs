@@ -0,0 +1,22 @@
+//! Solidity's function state mutability modifiers.
+
+/// Whether a function reads, writes, or moves value, as declared by its
+/// `stateMutability` ABI field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateMutability {
+	/// Does not read or modify contract state.
+	Pure,
+	/// Reads but does not modify contract state.
+	View,
+	/// May modify contract state; cannot receive Ether.
+	NonPayable,
+	/// May modify contract state and receive Ether.
+	Payable,
+}
+
+impl Default for StateMutability {
+	fn default() -> Self {
+		StateMutability::NonPayable
+	}
+}
@@ -0,0 +1,263 @@
+//! Conversions between native Rust types and `Token`s.
+
+use {Address, Bytes, ErrorKind, Result, Token, U256};
+
+/// Converts a Rust value (or tuple of values) into a list of `Token`s
+/// suitable for `Function::encode_input_typed`.
+pub trait Tokenize {
+	/// Converts `self` into a vector of tokens.
+	fn into_tokens(self) -> Vec<Token>;
+}
+
+/// Reconstructs a Rust value (or tuple of values) from the `Token`s
+/// returned by `Function::decode_output_typed`.
+pub trait Detokenize: Sized {
+	/// Creates a new instance from a vector of tokens.
+	fn from_tokens(tokens: Vec<Token>) -> Result<Self>;
+}
+
+impl Tokenize for Token {
+	fn into_tokens(self) -> Vec<Token> {
+		vec![self]
+	}
+}
+
+macro_rules! impl_tokenize_for_primitive {
+	($rust_type: ty, $token_variant: ident) => {
+		impl Tokenize for $rust_type {
+			fn into_tokens(self) -> Vec<Token> {
+				vec![Token::$token_variant(self.into())]
+			}
+		}
+	};
+}
+
+impl_tokenize_for_primitive!(Address, Address);
+impl_tokenize_for_primitive!(bool, Bool);
+// `Bytes` is this crate's `Vec<u8>` alias. It gets its own impl rather than
+// going through the blanket `impl<T: Tokenize> Tokenize for Vec<T>` below,
+// which is why `u8` deliberately has no `Tokenize` impl of its own — the two
+// would otherwise overlap (coherence error E0119) on `Vec<u8>`.
+impl_tokenize_for_primitive!(Bytes, Bytes);
+impl_tokenize_for_primitive!(String, String);
+
+macro_rules! impl_tokenize_for_uint {
+	($rust_type: ty) => {
+		impl Tokenize for $rust_type {
+			fn into_tokens(self) -> Vec<Token> {
+				vec![Token::Uint(self.into())]
+			}
+		}
+	};
+}
+
+impl_tokenize_for_uint!(u16);
+impl_tokenize_for_uint!(u32);
+impl_tokenize_for_uint!(u64);
+impl_tokenize_for_uint!(U256);
+
+impl<T: Tokenize> Tokenize for Vec<T> {
+	fn into_tokens(self) -> Vec<Token> {
+		vec![Token::Array(
+			self.into_iter().flat_map(Tokenize::into_tokens).collect(),
+		)]
+	}
+}
+
+macro_rules! impl_tokenize_for_array {
+	($len: expr) => {
+		impl<T: Tokenize> Tokenize for [T; $len] {
+			fn into_tokens(self) -> Vec<Token> {
+				vec![Token::FixedArray(
+					::std::array::IntoIter::new(self)
+						.flat_map(Tokenize::into_tokens)
+						.collect(),
+				)]
+			}
+		}
+	};
+}
+
+impl_tokenize_for_array!(1);
+impl_tokenize_for_array!(2);
+impl_tokenize_for_array!(3);
+impl_tokenize_for_array!(4);
+impl_tokenize_for_array!(8);
+impl_tokenize_for_array!(16);
+impl_tokenize_for_array!(32);
+
+impl Detokenize for Token {
+	fn from_tokens(mut tokens: Vec<Token>) -> Result<Self> {
+		match tokens.len() {
+			1 => Ok(tokens.remove(0)),
+			_ => Err(ErrorKind::InvalidData.into()),
+		}
+	}
+}
+
+macro_rules! impl_detokenize_for_primitive {
+	($rust_type: ty, $token_variant: ident) => {
+		impl Detokenize for $rust_type {
+			fn from_tokens(mut tokens: Vec<Token>) -> Result<Self> {
+				if tokens.len() != 1 {
+					return Err(ErrorKind::InvalidData.into());
+				}
+				match tokens.remove(0) {
+					Token::$token_variant(value) => Ok(value.into()),
+					_ => Err(ErrorKind::InvalidData.into()),
+				}
+			}
+		}
+	};
+}
+
+impl_detokenize_for_primitive!(Address, Address);
+impl_detokenize_for_primitive!(bool, Bool);
+impl_detokenize_for_primitive!(Bytes, Bytes);
+impl_detokenize_for_primitive!(String, String);
+
+impl Detokenize for U256 {
+	fn from_tokens(mut tokens: Vec<Token>) -> Result<Self> {
+		if tokens.len() != 1 {
+			return Err(ErrorKind::InvalidData.into());
+		}
+		match tokens.remove(0) {
+			Token::Uint(value) => Ok(value),
+			_ => Err(ErrorKind::InvalidData.into()),
+		}
+	}
+}
+
+macro_rules! impl_detokenize_for_uint {
+	($rust_type: ty) => {
+		impl Detokenize for $rust_type {
+			fn from_tokens(mut tokens: Vec<Token>) -> Result<Self> {
+				if tokens.len() != 1 {
+					return Err(ErrorKind::InvalidData.into());
+				}
+				match tokens.remove(0) {
+					Token::Uint(value) => Ok(value.low_u64() as $rust_type),
+					_ => Err(ErrorKind::InvalidData.into()),
+				}
+			}
+		}
+	};
+}
+
+impl_detokenize_for_uint!(u16);
+impl_detokenize_for_uint!(u32);
+impl_detokenize_for_uint!(u64);
+
+impl<T: Detokenize> Detokenize for Vec<T> {
+	fn from_tokens(mut tokens: Vec<Token>) -> Result<Self> {
+		if tokens.len() != 1 {
+			return Err(ErrorKind::InvalidData.into());
+		}
+		match tokens.remove(0) {
+			Token::Array(elements) => elements
+				.into_iter()
+				.map(|token| T::from_tokens(vec![token]))
+				.collect(),
+			_ => Err(ErrorKind::InvalidData.into()),
+		}
+	}
+}
+
+macro_rules! impl_detokenize_for_array {
+	($len: expr) => {
+		impl<T: Detokenize> Detokenize for [T; $len] {
+			fn from_tokens(mut tokens: Vec<Token>) -> Result<Self> {
+				if tokens.len() != 1 {
+					return Err(ErrorKind::InvalidData.into());
+				}
+				let elements = match tokens.remove(0) {
+					Token::FixedArray(elements) => elements,
+					_ => return Err(ErrorKind::InvalidData.into()),
+				};
+				if elements.len() != $len {
+					return Err(ErrorKind::InvalidData.into());
+				}
+
+				let values = elements
+					.into_iter()
+					.map(|token| T::from_tokens(vec![token]))
+					.collect::<Result<Vec<T>>>()?;
+				values.try_into().map_err(|_| ErrorKind::InvalidData.into())
+			}
+		}
+	};
+}
+
+impl_detokenize_for_array!(1);
+impl_detokenize_for_array!(2);
+impl_detokenize_for_array!(3);
+impl_detokenize_for_array!(4);
+impl_detokenize_for_array!(8);
+impl_detokenize_for_array!(16);
+impl_detokenize_for_array!(32);
+
+/// Implements `Tokenize`/`Detokenize` for a tuple of the given arity by
+/// concatenating (or splitting) the tokens of every member in order.
+macro_rules! impl_tokenize_for_tuple {
+	($num: expr, $( $ty: ident : $idx: tt ),+) => {
+		impl<$($ty: Tokenize,)+> Tokenize for ($($ty,)+) {
+			fn into_tokens(self) -> Vec<Token> {
+				let mut tokens = Vec::with_capacity($num);
+				$(tokens.extend(self.$idx.into_tokens());)+
+				tokens
+			}
+		}
+
+		impl<$($ty: Detokenize,)+> Detokenize for ($($ty,)+) {
+			fn from_tokens(tokens: Vec<Token>) -> Result<Self> {
+				if tokens.len() != $num {
+					return Err(ErrorKind::InvalidData.into());
+				}
+				let mut tokens = tokens.into_iter();
+				Ok(($($ty::from_tokens(vec![tokens.next().unwrap()])?,)+))
+			}
+		}
+	};
+}
+
+impl_tokenize_for_tuple!(1, A:0);
+impl_tokenize_for_tuple!(2, A:0, B:1);
+impl_tokenize_for_tuple!(3, A:0, B:1, C:2);
+impl_tokenize_for_tuple!(4, A:0, B:1, C:2, D:3);
+impl_tokenize_for_tuple!(5, A:0, B:1, C:2, D:3, E:4);
+impl_tokenize_for_tuple!(6, A:0, B:1, C:2, D:3, E:4, F:5);
+impl_tokenize_for_tuple!(7, A:0, B:1, C:2, D:3, E:4, F:5, G:6);
+impl_tokenize_for_tuple!(8, A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7);
+impl_tokenize_for_tuple!(9, A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8);
+impl_tokenize_for_tuple!(10, A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9);
+impl_tokenize_for_tuple!(11, A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10);
+impl_tokenize_for_tuple!(12, A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10, L:11);
+impl_tokenize_for_tuple!(13, A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10, L:11, M:12);
+impl_tokenize_for_tuple!(14, A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10, L:11, M:12, N:13);
+impl_tokenize_for_tuple!(15, A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10, L:11, M:12, N:13, O:14);
+impl_tokenize_for_tuple!(16, A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10, L:11, M:12, N:13, O:14, P:15);
+
+#[cfg(test)]
+mod tests {
+	use super::{Detokenize, Tokenize};
+	use {Token, U256};
+
+	#[test]
+	fn u256_round_trips_through_tokenize_and_detokenize() {
+		let value = U256::from(12345u64);
+		let tokens = Tokenize::into_tokens(value);
+		assert_eq!(tokens, vec![Token::Uint(value)]);
+
+		let decoded: U256 = Detokenize::from_tokens(tokens).unwrap();
+		assert_eq!(decoded, value);
+	}
+
+	#[test]
+	fn fixed_array_round_trips_through_tokenize_and_detokenize() {
+		let value: [U256; 3] = [U256::from(1u64), U256::from(2u64), U256::from(3u64)];
+		let tokens = Tokenize::into_tokens(value);
+
+		let decoded: [U256; 3] = Detokenize::from_tokens(tokens).unwrap();
+		assert_eq!(decoded, value);
+	}
+}